@@ -0,0 +1,70 @@
+//! [`Int`] bitwise NOT operations.
+
+use core::ops::Not;
+
+use crate::{Int, Uint, Wrapping};
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    /// Computes bitwise `!a`.
+    #[inline(always)]
+    pub const fn not(&self) -> Self {
+        Self(Uint::not(&self.0))
+    }
+
+    /// Perform wrapping bitwise `NOT`.
+    ///
+    /// There's no way wrapping could ever happen.
+    /// This function exists so that all operations are accounted for in the wrapping operations
+    pub const fn wrapping_not(&self) -> Self {
+        self.not()
+    }
+}
+
+impl<const LIMBS: usize> Not for Int<LIMBS> {
+    type Output = Self;
+
+    fn not(self) -> Int<LIMBS> {
+        Int::not(&self)
+    }
+}
+
+impl<const LIMBS: usize> Not for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn not(self) -> Int<LIMBS> {
+        Int::not(self)
+    }
+}
+
+impl<const LIMBS: usize> Not for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn not(self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.not())
+    }
+}
+
+impl<const LIMBS: usize> Not for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn not(self) -> Wrapping<Int<LIMBS>> {
+        Wrapping((&self.0).not())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::I128;
+
+    #[test]
+    fn not_ok() {
+        assert_eq!(I128::ZERO.not(), I128::MINUS_ONE);
+        assert_eq!(I128::MINUS_ONE.not(), I128::ZERO);
+    }
+
+    #[test]
+    fn wrapping_not_ok() {
+        assert_eq!(I128::ZERO.wrapping_not(), I128::MINUS_ONE);
+        assert_eq!(I128::MINUS_ONE.wrapping_not(), I128::ZERO);
+    }
+}
@@ -0,0 +1,78 @@
+//! [`Int`] formatting trait implementations.
+//!
+//! `Int` has no decimal `Display` impl: like [`Uint`][`crate::Uint`], it has no cheap base-10
+//! conversion, so only the radix formatters that operate directly on the two's-complement bit
+//! pattern are provided.
+
+use core::fmt;
+
+use crate::{Int, Wrapping};
+
+impl<const LIMBS: usize> fmt::Binary for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Octal for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Binary for Wrapping<Int<LIMBS>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Octal for Wrapping<Int<LIMBS>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for Wrapping<Int<LIMBS>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for Wrapping<Int<LIMBS>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::I128;
+
+    #[test]
+    fn lower_hex_two_complement() {
+        assert_eq!(format!("{:x}", I128::MINUS_ONE), "f".repeat(32));
+        assert_eq!(format!("{:x}", I128::ZERO), "0".repeat(32));
+    }
+
+    #[test]
+    fn upper_hex_two_complement() {
+        assert_eq!(format!("{:X}", I128::MINUS_ONE), "F".repeat(32));
+    }
+
+    #[test]
+    fn binary_two_complement() {
+        assert_eq!(format!("{:b}", I128::MINUS_ONE), "1".repeat(128));
+    }
+}
@@ -0,0 +1,231 @@
+//! [`Wrapping`] arithmetic operations for [`Int`].
+//!
+//! `Div`/`Rem` are intentionally omitted: signed division has no infallible "wrapping" form in
+//! this crate (it is exposed via `checked_div`/`div_rem`), matching `Wrapping<Uint>`'s surface.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+
+use crate::{Int, Wrapping};
+
+impl<const LIMBS: usize> Add for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Add<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn add(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Add<Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn add(self, rhs: Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Add<&Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn add(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> AddAssign for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn add_assign(&mut self, other: &Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const LIMBS: usize> Sub for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Sub<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn sub(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Sub<Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn sub(self, rhs: Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Sub<&Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn sub(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> SubAssign for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn sub_assign(&mut self, other: &Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> Mul for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Mul<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn mul(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Mul<Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn mul(self, rhs: Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Mul<&Wrapping<Int<LIMBS>>> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn mul(self, rhs: &Wrapping<Int<LIMBS>>) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> MulAssign for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> MulAssign<&Wrapping<Int<LIMBS>>> for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn mul_assign(&mut self, other: &Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> Neg for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn neg(self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_neg())
+    }
+}
+
+impl<const LIMBS: usize> Neg for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn neg(self) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_neg())
+    }
+}
+
+impl<const LIMBS: usize> Shl<u32> for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_shl(rhs))
+    }
+}
+
+impl<const LIMBS: usize> Shl<u32> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn shl(self, rhs: u32) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_shl(rhs))
+    }
+}
+
+impl<const LIMBS: usize> ShlAssign<u32> for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const LIMBS: usize> Shr<u32> for Wrapping<Int<LIMBS>> {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_shr(rhs))
+    }
+}
+
+impl<const LIMBS: usize> Shr<u32> for &Wrapping<Int<LIMBS>> {
+    type Output = Wrapping<Int<LIMBS>>;
+
+    fn shr(self, rhs: u32) -> Wrapping<Int<LIMBS>> {
+        Wrapping(self.0.wrapping_shr(rhs))
+    }
+}
+
+impl<const LIMBS: usize> ShrAssign<u32> for Wrapping<Int<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = *self >> rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I128, Wrapping};
+
+    #[test]
+    fn add_ok() {
+        assert_eq!(
+            Wrapping(I128::ONE) + Wrapping(I128::ONE),
+            Wrapping(I128::from(2i64))
+        );
+    }
+
+    #[test]
+    fn sub_wraps_on_overflow() {
+        assert_eq!(Wrapping(I128::MIN) - Wrapping(I128::ONE), Wrapping(I128::MAX));
+    }
+
+    #[test]
+    fn neg_wraps_on_min() {
+        assert_eq!(-Wrapping(I128::MIN), Wrapping(I128::MIN));
+    }
+}